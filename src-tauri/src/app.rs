@@ -0,0 +1,211 @@
+use std::{thread, time::Duration};
+
+use arboard::Clipboard;
+use enigo::Key;
+#[cfg(not(debug_assertions))]
+use enigo::{Direction, Enigo, Keyboard, Settings};
+use tauri::{Emitter, Manager, WebviewWindowBuilder};
+
+/// Startup configuration loaded before the Tauri context is built so that any
+/// extra webview arguments take effect on the very first window.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebviewConfig {
+    /// Extra arguments forwarded verbatim to the underlying webview at
+    /// window-creation time (mirrors Tauri's `additional_browser_args`), e.g.
+    /// `--disable-web-security` for local tooling or GPU flags for troublesome
+    /// Linux setups.
+    #[serde(default)]
+    pub additional_browser_args: Vec<String>,
+    /// Custom user-agent string used for OpenCode API calls.
+    #[serde(default)]
+    pub user_agent: Option<String>,
+}
+
+impl WebviewConfig {
+    /// Load the startup config as JSON from the `OPENCODEUI_WEBVIEW_CONFIG`
+    /// environment variable, falling back to defaults when it is unset or does
+    /// not parse so a missing config never blocks launch.
+    fn load() -> Self {
+        std::env::var("OPENCODEUI_WEBVIEW_CONFIG")
+            .ok()
+            .and_then(|raw| serde_json::from_str(&raw).ok())
+            .unwrap_or_default()
+    }
+}
+
+/// Paste chord used to drop the staged clipboard contents into the target
+/// window. Defaults to the platform-native paste shortcut but can be overridden
+/// by the frontend so power users can target terminal-paste variants
+/// (e.g. `Ctrl+Shift+V`).
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PasteShortcut {
+    /// Modifier keys held while the `key` is tapped, e.g. `["control", "shift"]`.
+    #[serde(default)]
+    pub modifiers: Vec<String>,
+    /// The key pressed together with the modifiers. Defaults to `v`.
+    #[serde(default = "default_paste_key")]
+    pub key: char,
+}
+
+fn default_paste_key() -> char {
+    'v'
+}
+
+impl Default for PasteShortcut {
+    fn default() -> Self {
+        // Cmd+V on macOS, Ctrl+V everywhere else.
+        #[cfg(target_os = "macos")]
+        let modifiers = vec!["meta".to_string()];
+        #[cfg(not(target_os = "macos"))]
+        let modifiers = vec!["control".to_string()];
+
+        Self {
+            modifiers,
+            key: default_paste_key(),
+        }
+    }
+}
+
+fn parse_modifier(name: &str) -> Option<Key> {
+    match name.to_ascii_lowercase().as_str() {
+        "control" | "ctrl" => Some(Key::Control),
+        "shift" => Some(Key::Shift),
+        "alt" | "option" => Some(Key::Alt),
+        "meta" | "cmd" | "command" | "super" => Some(Key::Meta),
+        _ => None,
+    }
+}
+
+/// Push `text` into whatever editor or terminal currently has OS focus.
+///
+/// The payload is staged on the system clipboard, focus is handed back to the
+/// previously active window with Alt+Tab, and the paste chord is issued. When
+/// `restore_clipboard` is set the prior clipboard contents are put back once the
+/// paste has landed.
+#[tauri::command]
+fn inject_into_editor(
+    text: String,
+    shortcut: Option<PasteShortcut>,
+    restore_clipboard: bool,
+) -> Result<(), String> {
+    // An empty payload would only produce a stray Alt+Tab flicker.
+    if text.is_empty() {
+        return Ok(());
+    }
+
+    let mut clipboard = Clipboard::new().map_err(|e| e.to_string())?;
+    let previous = if restore_clipboard {
+        clipboard.get_text().ok()
+    } else {
+        None
+    };
+    clipboard.set_text(text.clone()).map_err(|e| e.to_string())?;
+
+    let shortcut = shortcut.unwrap_or_default();
+
+    // Resolve the modifier keys up front and fail loudly on anything we don't
+    // recognise — silently dropping a typo'd modifier would press a bare
+    // `Key::Unicode` and type a literal character into the user's document.
+    let modifiers: Vec<Key> = shortcut
+        .modifiers
+        .iter()
+        .map(|m| parse_modifier(m).ok_or_else(|| format!("unknown modifier: {m}")))
+        .collect::<Result<_, _>>()?;
+
+    // Only emit real keystrokes in release builds; dev runs just log the
+    // payload so an automated test or a local `tauri dev` session never steals
+    // focus from the developer.
+    #[cfg(not(debug_assertions))]
+    {
+        let mut enigo = Enigo::new(&Settings::default()).map_err(|e| e.to_string())?;
+
+        // Return focus to the window that was active before OpenCodeUI.
+        enigo
+            .key(Key::Alt, Direction::Press)
+            .map_err(|e| e.to_string())?;
+        enigo
+            .key(Key::Tab, Direction::Click)
+            .map_err(|e| e.to_string())?;
+        enigo
+            .key(Key::Alt, Direction::Release)
+            .map_err(|e| e.to_string())?;
+
+        // Give the window manager a moment to settle the focus change.
+        thread::sleep(Duration::from_millis(200));
+
+        for m in &modifiers {
+            enigo.key(*m, Direction::Press).map_err(|e| e.to_string())?;
+        }
+        enigo
+            .key(Key::Unicode(shortcut.key), Direction::Click)
+            .map_err(|e| e.to_string())?;
+        for m in modifiers.iter().rev() {
+            enigo
+                .key(*m, Direction::Release)
+                .map_err(|e| e.to_string())?;
+        }
+    }
+
+    #[cfg(debug_assertions)]
+    {
+        let _ = &modifiers;
+        log::debug!(
+            "inject_into_editor (dev, no keystrokes): {} chars, shortcut {:?}, payload {:?}",
+            text.chars().count(),
+            shortcut,
+            text
+        );
+    }
+
+    // Keep the `Clipboard` owner alive long enough for the target app to read
+    // the selection. On X11 the contents are served by this process, so
+    // dropping it too early would make the paste land empty.
+    thread::sleep(Duration::from_millis(100));
+
+    if restore_clipboard {
+        if let Some(prev) = previous {
+            clipboard.set_text(prev).map_err(|e| e.to_string())?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg_attr(mobile, tauri::mobile_entry_point)]
+pub fn run() {
+    // Load before `generate_context!()` so the flags apply to the first window.
+    let config = WebviewConfig::load();
+
+    tauri::Builder::default()
+        // Single-instance guard: a second launch focuses the running window and
+        // forwards its CLI arguments over IPC instead of spawning a duplicate.
+        .plugin(tauri_plugin_single_instance::init(|app, argv, _cwd| {
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.set_focus();
+                let _ = window.unminimize();
+            }
+            let _ = app.emit("single-instance", argv);
+        }))
+        .plugin(tauri_plugin_opener::init())
+        .setup(move |app| {
+            let mut builder =
+                WebviewWindowBuilder::new(app, "main", tauri::WebviewUrl::default())
+                    .title("OpenCodeUI");
+
+            if !config.additional_browser_args.is_empty() {
+                builder = builder
+                    .additional_browser_args(&config.additional_browser_args.join(" "));
+            }
+            if let Some(ua) = &config.user_agent {
+                builder = builder.user_agent(ua);
+            }
+
+            builder.build()?;
+            Ok(())
+        })
+        .invoke_handler(tauri::generate_handler![inject_into_editor])
+        .run(tauri::generate_context!())
+        .expect("error while running tauri application");
+}